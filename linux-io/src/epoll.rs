@@ -0,0 +1,192 @@
+//! A readiness-based event loop over `epoll`.
+
+use crate::{AsFd, Error, Result};
+use linux_unsafe::raw::V;
+
+/// An `epoll` instance for monitoring readiness of many descriptors at once.
+///
+/// This is intentionally allocation-free: the caller owns the buffer that
+/// [`Epoll::wait`] writes ready events into.
+pub struct Epoll {
+    fd: linux_unsafe::int,
+}
+
+impl Epoll {
+    #[inline]
+    pub fn new() -> Result<Self> {
+        let result = unsafe { linux_unsafe::epoll_create1(linux_unsafe::EPOLL_CLOEXEC) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|fd| Self {
+                fd: fd as linux_unsafe::int,
+            })
+            .map_err(|e| e.into())
+    }
+
+    /// Registers `fd` for the given `events`, tagging it with the opaque
+    /// `data` token that [`EpollEvent::data`] will later return.
+    #[inline]
+    pub fn add(&mut self, fd: &impl AsFd, events: EventFlags, data: u64) -> Result<()> {
+        self.ctl(linux_unsafe::EPOLL_CTL_ADD, fd.as_fd(), events, data)
+    }
+
+    /// Changes the registered events and/or data token for an already-added
+    /// descriptor.
+    #[inline]
+    pub fn modify(&mut self, fd: &impl AsFd, events: EventFlags, data: u64) -> Result<()> {
+        self.ctl(linux_unsafe::EPOLL_CTL_MOD, fd.as_fd(), events, data)
+    }
+
+    /// Removes a previously-added descriptor from this instance.
+    #[inline]
+    pub fn delete(&mut self, fd: &impl AsFd) -> Result<()> {
+        self.ctl(
+            linux_unsafe::EPOLL_CTL_DEL,
+            fd.as_fd(),
+            EventFlags::empty(),
+            0,
+        )
+    }
+
+    fn ctl(
+        &mut self,
+        op: linux_unsafe::int,
+        fd: linux_unsafe::int,
+        events: EventFlags,
+        data: u64,
+    ) -> Result<()> {
+        let mut raw_event = RawEpollEvent {
+            events: events.bits(),
+            data,
+        };
+        let event_ptr = &mut raw_event as *mut RawEpollEvent as *mut linux_unsafe::void;
+        let result = unsafe { linux_unsafe::epoll_ctl(self.fd, op, fd, event_ptr) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|_| ())
+            .map_err(|e| e.into())
+    }
+
+    /// Blocks until at least one registered descriptor is ready, the
+    /// timeout elapses, or the call is interrupted by a signal.
+    ///
+    /// `timeout_ms` of `-1` waits indefinitely and `0` polls without
+    /// blocking. Ready events are written into `out`; the return value is
+    /// how many of its entries were filled. A call interrupted by a signal
+    /// fails with an error for which [`Error::is_interrupted`] is true,
+    /// leaving it to the caller to decide whether to restart the wait.
+    pub fn wait(&mut self, out: &mut [EpollEvent], timeout_ms: linux_unsafe::int) -> Result<usize> {
+        let out_ptr = out.as_mut_ptr() as *mut linux_unsafe::void;
+        let out_len = out.len() as linux_unsafe::int;
+        let result = unsafe { linux_unsafe::epoll_wait(self.fd, out_ptr, out_len, timeout_ms) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|v| v as usize)
+            .map_err(|e| e.into())
+    }
+}
+
+impl Drop for Epoll {
+    /// Attempts to close the underlying epoll descriptor when it's no
+    /// longer in scope, ignoring any error in the same way as [`crate::File`].
+    fn drop(&mut self) {
+        unsafe { linux_unsafe::close(self.fd) };
+    }
+}
+
+/// The kernel's `epoll_event` layout, used only for the `epoll_ctl` calls
+/// that register/update interest.
+///
+/// Only x86_64's ABI packs this struct (glibc's `__EPOLL_PACKED`, set via
+/// `__attribute__((packed))` only under `__x86_64__`). 32-bit x86 aligns `u64`
+/// to just 4 bytes, so its natural layout is already the same 12 bytes. Every
+/// other architecture this crate targets, including riscv64, leaves the
+/// kernel's natural 4 bytes of padding between `events` and `data` so `u64`
+/// stays 8-byte aligned. Packing unconditionally would shift `data` into that
+/// padding and corrupt both fields on those architectures.
+#[repr(C)]
+#[cfg_attr(target_arch = "x86_64", repr(packed))]
+struct RawEpollEvent {
+    events: u32,
+    data: u64,
+}
+
+/// A single ready event, as reported by [`Epoll::wait`].
+///
+/// Shares `RawEpollEvent`'s layout (including its x86_64-only packing) so a
+/// slice of these can be passed directly to `epoll_wait`.
+#[repr(C)]
+#[cfg_attr(target_arch = "x86_64", repr(packed))]
+#[derive(Clone, Copy)]
+pub struct EpollEvent {
+    events: u32,
+    data: u64,
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+const _: () = {
+    assert!(core::mem::size_of::<RawEpollEvent>() == 12);
+    assert!(core::mem::offset_of!(RawEpollEvent, data) == 4);
+    assert!(core::mem::size_of::<EpollEvent>() == 12);
+    assert!(core::mem::offset_of!(EpollEvent, data) == 4);
+};
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "x86")))]
+const _: () = {
+    assert!(core::mem::size_of::<RawEpollEvent>() == 16);
+    assert!(core::mem::offset_of!(RawEpollEvent, data) == 8);
+    assert!(core::mem::size_of::<EpollEvent>() == 16);
+    assert!(core::mem::offset_of!(EpollEvent, data) == 8);
+};
+
+impl EpollEvent {
+    /// An empty event record, for initializing the output slice passed to
+    /// [`Epoll::wait`].
+    #[inline]
+    pub const fn empty() -> Self {
+        Self { events: 0, data: 0 }
+    }
+
+    #[inline]
+    pub fn events(&self) -> EventFlags {
+        EventFlags(self.events)
+    }
+
+    #[inline]
+    pub fn data(&self) -> u64 {
+        self.data
+    }
+}
+
+/// A set of `epoll` readiness/behavior flags.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EventFlags(u32);
+
+impl EventFlags {
+    pub const IN: Self = Self(linux_unsafe::EPOLLIN as u32);
+    pub const OUT: Self = Self(linux_unsafe::EPOLLOUT as u32);
+    pub const ERR: Self = Self(linux_unsafe::EPOLLERR as u32);
+    pub const HUP: Self = Self(linux_unsafe::EPOLLHUP as u32);
+    pub const EDGE_TRIGGERED: Self = Self(linux_unsafe::EPOLLET as u32);
+
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+impl core::ops::BitOr for EventFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
@@ -0,0 +1,105 @@
+//! Scatter/gather I/O buffers, for use with [`File::read_vectored`] and
+//! [`File::write_vectored`].
+
+use crate::{Error, File, Result};
+use linux_unsafe::raw::V;
+
+/// The kernel's limit on the number of buffers accepted by a single
+/// `readv`/`writev`-family call.
+const IOV_MAX: usize = 1024;
+
+/// A buffer to write into as part of a vectored read, laid out to match the
+/// kernel's `struct iovec` so a slice of these can be passed directly to
+/// `readv`/`preadv`.
+#[repr(C)]
+pub struct IoSliceMut<'a> {
+    ptr: *mut u8,
+    len: linux_unsafe::size_t,
+    _marker: core::marker::PhantomData<&'a mut [u8]>,
+}
+
+unsafe impl<'a> Send for IoSliceMut<'a> {}
+unsafe impl<'a> Sync for IoSliceMut<'a> {}
+
+impl<'a> IoSliceMut<'a> {
+    #[inline]
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self {
+            ptr: buf.as_mut_ptr(),
+            len: buf.len() as linux_unsafe::size_t,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// A buffer to read from as part of a vectored write, laid out to match the
+/// kernel's `struct iovec` so a slice of these can be passed directly to
+/// `writev`/`pwritev`.
+#[repr(C)]
+pub struct IoSlice<'a> {
+    ptr: *const u8,
+    len: linux_unsafe::size_t,
+    _marker: core::marker::PhantomData<&'a [u8]>,
+}
+
+unsafe impl<'a> Send for IoSlice<'a> {}
+unsafe impl<'a> Sync for IoSlice<'a> {}
+
+impl<'a> IoSlice<'a> {
+    #[inline]
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self {
+            ptr: buf.as_ptr(),
+            len: buf.len() as linux_unsafe::size_t,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+// `struct iovec` is just a pointer followed by a pointer-width length, with
+// no kernel-mandated packing, but assert the layout anyway so a future field
+// reordering here can't silently stop matching what `readv`/`writev` expect.
+const _: () = {
+    assert!(core::mem::size_of::<IoSlice<'_>>() == 2 * core::mem::size_of::<usize>());
+    assert!(core::mem::offset_of!(IoSlice<'_>, ptr) == 0);
+    assert!(core::mem::offset_of!(IoSlice<'_>, len) == core::mem::size_of::<usize>());
+    assert!(core::mem::size_of::<IoSliceMut<'_>>() == 2 * core::mem::size_of::<usize>());
+    assert!(core::mem::offset_of!(IoSliceMut<'_>, ptr) == 0);
+    assert!(core::mem::offset_of!(IoSliceMut<'_>, len) == core::mem::size_of::<usize>());
+};
+
+impl File {
+    /// Reads into multiple buffers in one call, using the `readv` syscall.
+    ///
+    /// Data is filled into `bufs` in order, with each buffer filled
+    /// completely before the next is used, same as `std`'s vectored reads.
+    #[inline]
+    pub fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> Result<usize> {
+        if bufs.len() > IOV_MAX {
+            return Err(Error::new(linux_unsafe::EINVAL));
+        }
+        let bufs_ptr = bufs.as_ptr() as *const linux_unsafe::void;
+        let bufs_len = bufs.len();
+        let result = unsafe { linux_unsafe::readv(self.fd, bufs_ptr, bufs_len) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|v| v as usize)
+            .map_err(|e| e.into())
+    }
+
+    /// Writes from multiple buffers in one call, using the `writev` syscall.
+    ///
+    /// Buffers are drained in order, with each buffer fully consumed before
+    /// the next is used, same as `std`'s vectored writes.
+    #[inline]
+    pub fn write_vectored(&mut self, bufs: &[IoSlice]) -> Result<usize> {
+        if bufs.len() > IOV_MAX {
+            return Err(Error::new(linux_unsafe::EINVAL));
+        }
+        let bufs_ptr = bufs.as_ptr() as *const linux_unsafe::void;
+        let bufs_len = bufs.len();
+        let result = unsafe { linux_unsafe::writev(self.fd, bufs_ptr, bufs_len) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|v| v as usize)
+            .map_err(|e| e.into())
+    }
+}
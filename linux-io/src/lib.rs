@@ -7,7 +7,7 @@
 
 /// An encapsulated Linux file descriptor.
 pub struct File {
-    fd: linux_unsafe::int,
+    pub(crate) fd: linux_unsafe::int,
 }
 
 use linux_unsafe::raw::V;
@@ -113,6 +113,92 @@ impl File {
         }
     }
 
+    /// Reads from the given offset without moving the file's current
+    /// position, using the `pread64` syscall.
+    ///
+    /// Unlike [`File::read`], this takes `&self` rather than `&mut self`
+    /// because it doesn't touch the shared file offset, so concurrent
+    /// positioned reads are safe.
+    #[inline]
+    pub fn read_at(&self, buf: &mut [u8], offset: u64) -> Result<usize> {
+        let buf_ptr = buf.as_mut_ptr() as *mut linux_unsafe::void;
+        let buf_size = buf.len();
+
+        #[cfg(not(target_pointer_width = "32"))]
+        {
+            let raw_offset = offset as linux_unsafe::loff_t;
+            let result = unsafe { linux_unsafe::pread64(self.fd, buf_ptr, buf_size, raw_offset) };
+            linux_unsafe::raw::unpack_standard_result(result as V)
+                .map(|v| v as usize)
+                .map_err(|e| e.into())
+        }
+
+        #[cfg(target_pointer_width = "32")]
+        {
+            // As with `_llseek`, 32-bit platforms take the 64-bit offset
+            // split across a hi/lo register pair rather than as one word.
+            //
+            // ARM EABI and the MIPS o32 ABI additionally require a 64-bit
+            // argument to start on an even-numbered register, so the raw
+            // syscall there takes an extra unused padding argument right
+            // before the offset pair to force that alignment; other 32-bit
+            // ABIs (e.g. x86) don't need it.
+            let offset_high = ((offset >> 32) as u32) as linux_unsafe::ulong;
+            let offset_low = (offset as u32) as linux_unsafe::ulong;
+            #[cfg(any(target_arch = "arm", target_arch = "mips"))]
+            let result = unsafe {
+                linux_unsafe::pread64(self.fd, buf_ptr, buf_size, 0, offset_low, offset_high)
+            };
+            #[cfg(not(any(target_arch = "arm", target_arch = "mips")))]
+            let result = unsafe {
+                linux_unsafe::pread64(self.fd, buf_ptr, buf_size, offset_low, offset_high)
+            };
+            linux_unsafe::raw::unpack_standard_result(result as V)
+                .map(|v| v as usize)
+                .map_err(|e| e.into())
+        }
+    }
+
+    /// Writes to the given offset without moving the file's current
+    /// position, using the `pwrite64` syscall.
+    ///
+    /// Unlike [`File::write`], this takes `&self` rather than `&mut self`
+    /// because it doesn't touch the shared file offset, so concurrent
+    /// positioned writes are safe.
+    #[inline]
+    pub fn write_at(&self, buf: &[u8], offset: u64) -> Result<usize> {
+        let buf_ptr = buf.as_ptr() as *const linux_unsafe::void;
+        let buf_size = buf.len();
+
+        #[cfg(not(target_pointer_width = "32"))]
+        {
+            let raw_offset = offset as linux_unsafe::loff_t;
+            let result = unsafe { linux_unsafe::pwrite64(self.fd, buf_ptr, buf_size, raw_offset) };
+            linux_unsafe::raw::unpack_standard_result(result as V)
+                .map(|v| v as usize)
+                .map_err(|e| e.into())
+        }
+
+        #[cfg(target_pointer_width = "32")]
+        {
+            // See the matching comment in `read_at` for why arm/mips need an
+            // extra padding argument here and other 32-bit ABIs don't.
+            let offset_high = ((offset >> 32) as u32) as linux_unsafe::ulong;
+            let offset_low = (offset as u32) as linux_unsafe::ulong;
+            #[cfg(any(target_arch = "arm", target_arch = "mips"))]
+            let result = unsafe {
+                linux_unsafe::pwrite64(self.fd, buf_ptr, buf_size, 0, offset_low, offset_high)
+            };
+            #[cfg(not(any(target_arch = "arm", target_arch = "mips")))]
+            let result = unsafe {
+                linux_unsafe::pwrite64(self.fd, buf_ptr, buf_size, offset_low, offset_high)
+            };
+            linux_unsafe::raw::unpack_standard_result(result as V)
+                .map(|v| v as usize)
+                .map_err(|e| e.into())
+        }
+    }
+
     #[inline]
     pub fn sync(&mut self) -> Result<()> {
         let result = unsafe { linux_unsafe::syncfs(self.fd) };
@@ -132,6 +218,20 @@ impl File {
     }
 }
 
+/// Types backed by a raw Linux file descriptor, for use with APIs that
+/// operate across descriptor-based types, such as [`Epoll`].
+pub trait AsFd {
+    /// Returns the underlying file descriptor without transferring ownership.
+    fn as_fd(&self) -> linux_unsafe::int;
+}
+
+impl AsFd for File {
+    #[inline]
+    fn as_fd(&self) -> linux_unsafe::int {
+        self.fd
+    }
+}
+
 impl Drop for File {
     /// Attempts to close the file when it's no longer in scope.
     ///
@@ -152,6 +252,15 @@ impl std::io::Read for File {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         self.read(buf).map_err(|e| e.into())
     }
+
+    fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize> {
+        // `std::io::IoSliceMut` wraps the same `iovec` layout on Linux, so
+        // the slice can be reinterpreted in place rather than copied.
+        let bufs = unsafe {
+            core::slice::from_raw_parts_mut(bufs.as_mut_ptr() as *mut IoSliceMut, bufs.len())
+        };
+        self.read_vectored(bufs).map_err(|e| e.into())
+    }
 }
 
 #[cfg(feature = "std")]
@@ -160,6 +269,12 @@ impl std::io::Write for File {
         self.write(buf).map_err(|e| e.into())
     }
 
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+        let bufs =
+            unsafe { core::slice::from_raw_parts(bufs.as_ptr() as *const IoSlice, bufs.len()) };
+        self.write_vectored(bufs).map_err(|e| e.into())
+    }
+
     fn flush(&mut self) -> std::io::Result<()> {
         self.sync().map_err(|e| e.into())
     }
@@ -172,6 +287,17 @@ impl std::io::Seek for File {
     }
 }
 
+#[cfg(feature = "std")]
+impl std::os::unix::fs::FileExt for File {
+    fn read_at(&self, buf: &mut [u8], offset: u64) -> std::io::Result<usize> {
+        self.read_at(buf, offset).map_err(|e| e.into())
+    }
+
+    fn write_at(&self, buf: &[u8], offset: u64) -> std::io::Result<usize> {
+        self.write_at(buf, offset).map_err(|e| e.into())
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::os::fd::FromRawFd for File {
     unsafe fn from_raw_fd(fd: std::os::fd::RawFd) -> Self {
@@ -207,6 +333,14 @@ impl Error {
     pub fn into_std_io_error(self) -> std::io::Error {
         std::io::Error::from_raw_os_error(self.0)
     }
+
+    /// Returns true if this error represents an interrupted system call
+    /// (`EINTR`). Callers of blocking APIs like [`Epoll::wait`] may want to
+    /// retry on this error rather than treat it as fatal.
+    #[inline]
+    pub const fn is_interrupted(&self) -> bool {
+        self.0 == linux_unsafe::EINTR
+    }
 }
 
 impl From<i32> for Error {
@@ -269,5 +403,25 @@ impl From<std::io::SeekFrom> for SeekFrom {
     }
 }
 
+mod iovec;
+pub use iovec::{IoSlice, IoSliceMut};
+
+mod dir;
+pub use dir::{Dir, DirEntry, FileType};
+
+mod epoll;
+pub use epoll::{Epoll, EpollEvent, EventFlags};
+
+mod metadata;
+pub use metadata::{stat_raw, Metadata, Permissions, Timestamp};
+
+mod socket;
+pub use socket::{Socket, SocketAddr, SocketAddrUnix, SocketAddrV4, SocketAddrV6, UNIX_PATH_MAX};
+#[cfg(feature = "std")]
+pub use socket::NotInetError;
+
+mod mmap;
+pub use mmap::{MapFlags, Mmap, ProtFlags};
+
 #[cfg(test)]
 mod tests;
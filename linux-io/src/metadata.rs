@@ -0,0 +1,184 @@
+//! File metadata via the `statx` syscall.
+//!
+//! `statx` is used in preference to the legacy `fstat`/`stat` so that this
+//! works uniformly across every architecture this crate targets, including
+//! riscv64, which never had the old `stat` syscalls.
+
+use crate::{File, FileType, Result};
+use core::mem::MaybeUninit;
+use linux_unsafe::raw::V;
+
+/// The `statx` fields this crate requests: the usual `fstat`-equivalent
+/// fields plus creation time, which `fstat` can't report at all.
+const STATX_MASK: linux_unsafe::uint = linux_unsafe::STATX_BASIC_STATS | linux_unsafe::STATX_BTIME;
+
+/// A point in time as reported by `statx`, expressed as seconds and
+/// nanoseconds since the Unix epoch.
+///
+/// This isn't `core::time::Duration` because `tv_sec` can be negative for
+/// timestamps before 1970, which `Duration` can't represent.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Timestamp {
+    pub seconds: i64,
+    pub nanoseconds: u32,
+}
+
+/// File permission bits, as reported by `statx`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Permissions(u16);
+
+impl Permissions {
+    /// The raw permission bits, in the same form as the low 12 bits of
+    /// `st_mode`/`stx_mode`.
+    #[inline]
+    pub fn mode(&self) -> u16 {
+        self.0
+    }
+}
+
+/// File metadata, as reported by the `statx` syscall.
+///
+/// Timestamp accessors return `None` if the corresponding bit is absent from
+/// the mask the kernel actually returned, which can happen on filesystems
+/// that don't track that particular timestamp. The size, type and permission
+/// accessors don't check the mask: they return whatever the kernel wrote into
+/// those fields.
+pub struct Metadata {
+    raw: RawStatx,
+}
+
+impl Metadata {
+    #[inline]
+    pub fn len(&self) -> u64 {
+        self.raw.stx_size
+    }
+
+    #[inline]
+    pub fn file_type(&self) -> FileType {
+        FileType::from_mode(self.raw.stx_mode as u32)
+    }
+
+    #[inline]
+    pub fn permissions(&self) -> Permissions {
+        Permissions((self.raw.stx_mode as u16) & 0o7777)
+    }
+
+    #[inline]
+    pub fn modified(&self) -> Option<Timestamp> {
+        self.timestamp_if_present(linux_unsafe::STATX_MTIME, self.raw.stx_mtime)
+    }
+
+    #[inline]
+    pub fn accessed(&self) -> Option<Timestamp> {
+        self.timestamp_if_present(linux_unsafe::STATX_ATIME, self.raw.stx_atime)
+    }
+
+    #[inline]
+    pub fn changed(&self) -> Option<Timestamp> {
+        self.timestamp_if_present(linux_unsafe::STATX_CTIME, self.raw.stx_ctime)
+    }
+
+    #[inline]
+    pub fn created(&self) -> Option<Timestamp> {
+        self.timestamp_if_present(linux_unsafe::STATX_BTIME, self.raw.stx_btime)
+    }
+
+    #[inline]
+    fn timestamp_if_present(
+        &self,
+        mask_bit: linux_unsafe::uint,
+        raw: RawStatxTimestamp,
+    ) -> Option<Timestamp> {
+        if self.raw.stx_mask & mask_bit == 0 {
+            return None;
+        }
+        Some(Timestamp {
+            seconds: raw.tv_sec,
+            nanoseconds: raw.tv_nsec,
+        })
+    }
+}
+
+impl File {
+    /// Returns metadata about this open file, via `statx` with
+    /// `AT_EMPTY_PATH` so no path needs to be re-resolved.
+    pub fn metadata(&self) -> Result<Metadata> {
+        let empty_path = b"\0";
+        let path_raw = empty_path.as_ptr() as *const linux_unsafe::char;
+        statx_raw(self.fd, path_raw, linux_unsafe::AT_EMPTY_PATH)
+    }
+}
+
+/// Calls `statx` directly on a path, without requiring an open [`File`].
+pub fn stat_raw(path: &[u8], flags: linux_unsafe::int) -> Result<Metadata> {
+    let path_raw = path.as_ptr() as *const linux_unsafe::char;
+    statx_raw(linux_unsafe::AT_FDCWD, path_raw, flags)
+}
+
+#[inline]
+fn statx_raw(
+    dir_fd: linux_unsafe::int,
+    path_raw: *const linux_unsafe::char,
+    flags: linux_unsafe::int,
+) -> Result<Metadata> {
+    // The kernel writes the whole structure on success, but start it zeroed
+    // so that no byte of it is ever uninitialized. Only the timestamp
+    // accessors consult the returned mask; the others read their fields as-is.
+    let mut buf: MaybeUninit<RawStatx> = MaybeUninit::zeroed();
+    let buf_ptr = buf.as_mut_ptr() as *mut linux_unsafe::void;
+    let result = unsafe { linux_unsafe::statx(dir_fd, path_raw, flags, STATX_MASK, buf_ptr) };
+    linux_unsafe::raw::unpack_standard_result(result as V)
+        .map(|_| Metadata {
+            raw: unsafe { buf.assume_init() },
+        })
+        .map_err(|e| e.into())
+}
+
+/// The kernel's `statx_timestamp` layout.
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[allow(dead_code)] // _reserved exists only to match the kernel's layout
+struct RawStatxTimestamp {
+    tv_sec: i64,
+    tv_nsec: u32,
+    _reserved: i32,
+}
+
+/// The kernel's `statx` layout. Field order and sizes must match the ABI
+/// exactly; trailing fields this crate doesn't expose yet are kept as an
+/// opaque reserved region rather than named out.
+#[repr(C)]
+#[allow(dead_code)] // most fields exist only to match the kernel's layout
+struct RawStatx {
+    stx_mask: u32,
+    stx_blksize: u32,
+    stx_attributes: u64,
+    stx_nlink: u32,
+    stx_uid: u32,
+    stx_gid: u32,
+    stx_mode: u16,
+    _spare0: u16,
+    stx_ino: u64,
+    stx_size: u64,
+    stx_blocks: u64,
+    stx_attributes_mask: u64,
+    stx_atime: RawStatxTimestamp,
+    stx_btime: RawStatxTimestamp,
+    stx_ctime: RawStatxTimestamp,
+    stx_mtime: RawStatxTimestamp,
+    _reserved: [u64; 16],
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<RawStatxTimestamp>() == 16);
+    assert!(core::mem::offset_of!(RawStatxTimestamp, tv_nsec) == 8);
+
+    assert!(core::mem::size_of::<RawStatx>() == 256);
+    assert!(core::mem::offset_of!(RawStatx, stx_mode) == 28);
+    assert!(core::mem::offset_of!(RawStatx, stx_ino) == 32);
+    assert!(core::mem::offset_of!(RawStatx, stx_size) == 40);
+    assert!(core::mem::offset_of!(RawStatx, stx_atime) == 64);
+    assert!(core::mem::offset_of!(RawStatx, stx_btime) == 80);
+    assert!(core::mem::offset_of!(RawStatx, stx_ctime) == 96);
+    assert!(core::mem::offset_of!(RawStatx, stx_mtime) == 112);
+};
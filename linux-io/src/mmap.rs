@@ -0,0 +1,149 @@
+//! Memory-mapped file access via `mmap`.
+
+use crate::{Error, File, Result};
+use linux_unsafe::raw::V;
+
+/// The page size assumed for alignment checks.
+///
+/// This is the value on every architecture this crate currently targets; if
+/// that changes, this should become a runtime `sysconf` query instead.
+const PAGE_SIZE: usize = 4096;
+
+impl File {
+    /// Maps part of this file into memory.
+    ///
+    /// `offset` must be a multiple of the page size; unlike the kernel, this
+    /// returns `EINVAL` immediately rather than only on some architectures.
+    /// `len` has no such requirement -- as with the kernel, a mapping whose
+    /// length isn't page-aligned is rounded up to the next page.
+    pub fn map(&self, len: usize, prot: ProtFlags, flags: MapFlags, offset: u64) -> Result<Mmap> {
+        if offset as usize % PAGE_SIZE != 0 {
+            return Err(Error::new(linux_unsafe::EINVAL));
+        }
+
+        let result = unsafe {
+            linux_unsafe::mmap(
+                core::ptr::null_mut(),
+                len,
+                prot.bits(),
+                flags.bits(),
+                self.fd,
+                offset as linux_unsafe::off_t,
+            )
+        };
+        // `mmap` signals failure the same way ordinary syscalls do -- a
+        // return value in `-4095..=-1` -- so the standard unpacking applies
+        // even though a successful result is a pointer, not a count.
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|v| Mmap {
+                ptr: v as usize as *mut u8,
+                len,
+            })
+            .map_err(|e| e.into())
+    }
+}
+
+/// A region of memory mapped from a file with [`File::map`].
+pub struct Mmap {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl Mmap {
+    /// Flushes changes made to this mapping back to the backing file.
+    pub fn flush(&self) -> Result<()> {
+        let ptr = self.ptr as *mut linux_unsafe::void;
+        let result = unsafe { linux_unsafe::msync(ptr, self.len, linux_unsafe::MS_SYNC) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|_| ())
+            .map_err(|e| e.into())
+    }
+
+    /// Changes the protection of this mapping.
+    pub fn protect(&mut self, prot: ProtFlags) -> Result<()> {
+        let ptr = self.ptr as *mut linux_unsafe::void;
+        let result = unsafe { linux_unsafe::mprotect(ptr, self.len, prot.bits()) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|_| ())
+            .map_err(|e| e.into())
+    }
+}
+
+impl core::ops::Deref for Mmap {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl core::ops::DerefMut for Mmap {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl Drop for Mmap {
+    /// Unmaps the region. Like [`File`]'s implicit close, this ignores any
+    /// error from `munmap`.
+    #[allow(unused_must_use)]
+    fn drop(&mut self) {
+        let ptr = self.ptr as *mut linux_unsafe::void;
+        unsafe { linux_unsafe::munmap(ptr, self.len) };
+    }
+}
+
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+/// Memory protection flags for [`File::map`] and [`Mmap::protect`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ProtFlags(linux_unsafe::int);
+
+impl ProtFlags {
+    pub const NONE: Self = Self(linux_unsafe::PROT_NONE);
+    pub const READ: Self = Self(linux_unsafe::PROT_READ);
+    pub const WRITE: Self = Self(linux_unsafe::PROT_WRITE);
+    pub const EXEC: Self = Self(linux_unsafe::PROT_EXEC);
+
+    #[inline]
+    pub const fn bits(self) -> linux_unsafe::int {
+        self.0
+    }
+}
+
+impl core::ops::BitOr for ProtFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Mapping behavior flags for [`File::map`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MapFlags(linux_unsafe::int);
+
+impl MapFlags {
+    pub const SHARED: Self = Self(linux_unsafe::MAP_SHARED);
+    pub const PRIVATE: Self = Self(linux_unsafe::MAP_PRIVATE);
+    pub const FIXED: Self = Self(linux_unsafe::MAP_FIXED);
+    pub const ANONYMOUS: Self = Self(linux_unsafe::MAP_ANONYMOUS);
+
+    #[inline]
+    pub const fn bits(self) -> linux_unsafe::int {
+        self.0
+    }
+}
+
+impl core::ops::BitOr for MapFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
@@ -0,0 +1,358 @@
+//! Networking via the `socket` family of syscalls.
+
+use crate::{AsFd, Error, Result};
+use linux_unsafe::raw::V;
+
+/// The size of `sockaddr_storage`, large enough to hold any address family
+/// this crate supports.
+const ADDR_BUF_SIZE: usize = 128;
+
+/// An encapsulated Linux socket descriptor, peer to [`crate::File`].
+pub struct Socket {
+    fd: linux_unsafe::int,
+}
+
+impl Socket {
+    #[inline]
+    pub fn new(
+        domain: linux_unsafe::int,
+        typ: linux_unsafe::int,
+        protocol: linux_unsafe::int,
+    ) -> Result<Self> {
+        let result = unsafe { linux_unsafe::socket(domain, typ, protocol) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|fd| Self {
+                fd: fd as linux_unsafe::int,
+            })
+            .map_err(|e| e.into())
+    }
+
+    #[inline]
+    pub fn bind(&mut self, addr: &SocketAddr) -> Result<()> {
+        let mut buf = [0u8; ADDR_BUF_SIZE];
+        let len = addr.write_raw(&mut buf);
+        let addr_ptr = buf.as_ptr() as *const linux_unsafe::void;
+        let result = unsafe { linux_unsafe::bind(self.fd, addr_ptr, len) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|_| ())
+            .map_err(|e| e.into())
+    }
+
+    #[inline]
+    pub fn listen(&mut self, backlog: linux_unsafe::int) -> Result<()> {
+        let result = unsafe { linux_unsafe::listen(self.fd, backlog) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|_| ())
+            .map_err(|e| e.into())
+    }
+
+    /// Accepts a pending connection, returning the new [`Socket`] and the
+    /// peer's address. `flags` is passed straight through to `accept4`, so
+    /// e.g. `SOCK_NONBLOCK`/`SOCK_CLOEXEC` can be requested atomically.
+    pub fn accept4(&mut self, flags: linux_unsafe::int) -> Result<(Socket, SocketAddr)> {
+        let mut buf = [0u8; ADDR_BUF_SIZE];
+        let mut len = buf.len() as linux_unsafe::socklen_t;
+        let addr_ptr = buf.as_mut_ptr() as *mut linux_unsafe::void;
+        let len_ptr = &mut len as *mut linux_unsafe::socklen_t;
+        let result = unsafe { linux_unsafe::accept4(self.fd, addr_ptr, len_ptr, flags) };
+        let fd = linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|fd| fd as linux_unsafe::int)
+            .map_err(Error::from)?;
+        // Wrap the accepted fd immediately so it's closed on drop if address
+        // parsing below fails, rather than leaking it.
+        let sock = Socket { fd };
+        let addr = SocketAddr::from_raw(&buf, len).ok_or(Error::new(linux_unsafe::EINVAL))?;
+        Ok((sock, addr))
+    }
+
+    #[inline]
+    pub fn connect(&mut self, addr: &SocketAddr) -> Result<()> {
+        let mut buf = [0u8; ADDR_BUF_SIZE];
+        let len = addr.write_raw(&mut buf);
+        let addr_ptr = buf.as_ptr() as *const linux_unsafe::void;
+        let result = unsafe { linux_unsafe::connect(self.fd, addr_ptr, len) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|_| ())
+            .map_err(|e| e.into())
+    }
+
+    #[inline]
+    pub fn send(&mut self, buf: &[u8], flags: linux_unsafe::int) -> Result<usize> {
+        let buf_ptr = buf.as_ptr() as *const linux_unsafe::void;
+        let buf_size = buf.len();
+        let result = unsafe { linux_unsafe::send(self.fd, buf_ptr, buf_size, flags) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|v| v as usize)
+            .map_err(|e| e.into())
+    }
+
+    #[inline]
+    pub fn recv(&mut self, buf: &mut [u8], flags: linux_unsafe::int) -> Result<usize> {
+        let buf_ptr = buf.as_mut_ptr() as *mut linux_unsafe::void;
+        let buf_size = buf.len();
+        let result = unsafe { linux_unsafe::recv(self.fd, buf_ptr, buf_size, flags) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|v| v as usize)
+            .map_err(|e| e.into())
+    }
+
+    pub fn sendto(
+        &mut self,
+        buf: &[u8],
+        flags: linux_unsafe::int,
+        addr: &SocketAddr,
+    ) -> Result<usize> {
+        let mut addr_buf = [0u8; ADDR_BUF_SIZE];
+        let addr_len = addr.write_raw(&mut addr_buf);
+        let buf_ptr = buf.as_ptr() as *const linux_unsafe::void;
+        let buf_size = buf.len();
+        let addr_ptr = addr_buf.as_ptr() as *const linux_unsafe::void;
+        let result =
+            unsafe { linux_unsafe::sendto(self.fd, buf_ptr, buf_size, flags, addr_ptr, addr_len) };
+        linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|v| v as usize)
+            .map_err(|e| e.into())
+    }
+
+    pub fn recvfrom(
+        &mut self,
+        buf: &mut [u8],
+        flags: linux_unsafe::int,
+    ) -> Result<(usize, SocketAddr)> {
+        let mut addr_buf = [0u8; ADDR_BUF_SIZE];
+        let mut addr_len = addr_buf.len() as linux_unsafe::socklen_t;
+        let buf_ptr = buf.as_mut_ptr() as *mut linux_unsafe::void;
+        let buf_size = buf.len();
+        let addr_ptr = addr_buf.as_mut_ptr() as *mut linux_unsafe::void;
+        let addr_len_ptr = &mut addr_len as *mut linux_unsafe::socklen_t;
+        let result = unsafe {
+            linux_unsafe::recvfrom(self.fd, buf_ptr, buf_size, flags, addr_ptr, addr_len_ptr)
+        };
+        let n = linux_unsafe::raw::unpack_standard_result(result as V)
+            .map(|v| v as usize)
+            .map_err(Error::from)?;
+        let addr =
+            SocketAddr::from_raw(&addr_buf, addr_len).ok_or(Error::new(linux_unsafe::EINVAL))?;
+        Ok((n, addr))
+    }
+}
+
+impl AsFd for Socket {
+    #[inline]
+    fn as_fd(&self) -> linux_unsafe::int {
+        self.fd
+    }
+}
+
+impl Drop for Socket {
+    /// Attempts to close the socket when it's no longer in scope, ignoring
+    /// any error in the same way as [`crate::File`].
+    fn drop(&mut self) {
+        unsafe { linux_unsafe::close(self.fd) };
+    }
+}
+
+/// A socket address, in one of the families this crate supports.
+///
+/// Serializes to and parses from the kernel's `sockaddr_storage` layout:
+/// a `u16` family tag followed by family-specific bytes, with ports in
+/// network byte order.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SocketAddr {
+    V4(SocketAddrV4),
+    V6(SocketAddrV6),
+    Unix(SocketAddrUnix),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SocketAddrV4 {
+    pub ip: [u8; 4],
+    pub port: u16,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SocketAddrV6 {
+    pub ip: [u8; 16],
+    pub port: u16,
+    pub flowinfo: u32,
+    pub scope_id: u32,
+}
+
+/// The maximum path length in `sockaddr_un.sun_path`, including the NUL
+/// terminator used for non-abstract paths.
+pub const UNIX_PATH_MAX: usize = 108;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SocketAddrUnix {
+    path: [u8; UNIX_PATH_MAX],
+    len: usize,
+}
+
+impl SocketAddrUnix {
+    /// Builds a Unix domain address from a filesystem path (or, on Linux, an
+    /// abstract-namespace name with a leading NUL).
+    ///
+    /// Fails with `EINVAL` if `path` is longer than [`UNIX_PATH_MAX`].
+    pub fn new(path: &[u8]) -> Result<Self> {
+        if path.len() > UNIX_PATH_MAX {
+            return Err(Error::new(linux_unsafe::EINVAL));
+        }
+        let mut buf = [0u8; UNIX_PATH_MAX];
+        buf[..path.len()].copy_from_slice(path);
+        Ok(Self {
+            path: buf,
+            len: path.len(),
+        })
+    }
+
+    #[inline]
+    pub fn path(&self) -> &[u8] {
+        &self.path[..self.len]
+    }
+}
+
+impl SocketAddr {
+    fn write_raw(&self, buf: &mut [u8; ADDR_BUF_SIZE]) -> linux_unsafe::socklen_t {
+        match self {
+            SocketAddr::V4(a) => {
+                buf[0..2].copy_from_slice(&(linux_unsafe::AF_INET as u16).to_ne_bytes());
+                buf[2..4].copy_from_slice(&a.port.to_be_bytes());
+                buf[4..8].copy_from_slice(&a.ip);
+                buf[8..16].fill(0);
+                16
+            }
+            SocketAddr::V6(a) => {
+                buf[0..2].copy_from_slice(&(linux_unsafe::AF_INET6 as u16).to_ne_bytes());
+                buf[2..4].copy_from_slice(&a.port.to_be_bytes());
+                buf[4..8].copy_from_slice(&a.flowinfo.to_ne_bytes());
+                buf[8..24].copy_from_slice(&a.ip);
+                buf[24..28].copy_from_slice(&a.scope_id.to_ne_bytes());
+                28
+            }
+            SocketAddr::Unix(a) => {
+                buf[0..2].copy_from_slice(&(linux_unsafe::AF_UNIX as u16).to_ne_bytes());
+                buf[2..2 + a.len].copy_from_slice(&a.path[..a.len]);
+                (2 + a.len) as linux_unsafe::socklen_t
+            }
+        }
+    }
+
+    fn from_raw(buf: &[u8], len: linux_unsafe::socklen_t) -> Option<Self> {
+        if (len as usize) < 2 {
+            return None;
+        }
+        let family = u16::from_ne_bytes(buf[0..2].try_into().unwrap());
+        match family as linux_unsafe::int {
+            linux_unsafe::AF_INET => {
+                let port = u16::from_be_bytes(buf[2..4].try_into().unwrap());
+                let mut ip = [0u8; 4];
+                ip.copy_from_slice(&buf[4..8]);
+                Some(SocketAddr::V4(SocketAddrV4 { ip, port }))
+            }
+            linux_unsafe::AF_INET6 => {
+                let port = u16::from_be_bytes(buf[2..4].try_into().unwrap());
+                let flowinfo = u32::from_ne_bytes(buf[4..8].try_into().unwrap());
+                let mut ip = [0u8; 16];
+                ip.copy_from_slice(&buf[8..24]);
+                let scope_id = u32::from_ne_bytes(buf[24..28].try_into().unwrap());
+                Some(SocketAddr::V6(SocketAddrV6 {
+                    ip,
+                    port,
+                    flowinfo,
+                    scope_id,
+                }))
+            }
+            linux_unsafe::AF_UNIX => {
+                let path_len = (len as usize).saturating_sub(2).min(UNIX_PATH_MAX);
+                let mut path = [0u8; UNIX_PATH_MAX];
+                path[..path_len].copy_from_slice(&buf[2..2 + path_len]);
+                Some(SocketAddr::Unix(SocketAddrUnix {
+                    path,
+                    len: path_len,
+                }))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::net::SocketAddr> for SocketAddr {
+    fn from(value: std::net::SocketAddr) -> Self {
+        match value {
+            std::net::SocketAddr::V4(v4) => SocketAddr::V4(SocketAddrV4 {
+                ip: v4.ip().octets(),
+                port: v4.port(),
+            }),
+            std::net::SocketAddr::V6(v6) => SocketAddr::V6(SocketAddrV6 {
+                ip: v6.ip().octets(),
+                port: v6.port(),
+                flowinfo: v6.flowinfo(),
+                scope_id: v6.scope_id(),
+            }),
+        }
+    }
+}
+
+/// Error returned when converting a [`SocketAddr::Unix`] to
+/// `std::net::SocketAddr`, which has no such variant.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct NotInetError;
+
+#[cfg(feature = "std")]
+impl core::convert::TryFrom<SocketAddr> for std::net::SocketAddr {
+    type Error = NotInetError;
+
+    fn try_from(value: SocketAddr) -> core::result::Result<Self, NotInetError> {
+        match value {
+            SocketAddr::V4(v4) => Ok(std::net::SocketAddr::V4(std::net::SocketAddrV4::new(
+                std::net::Ipv4Addr::from(v4.ip),
+                v4.port,
+            ))),
+            SocketAddr::V6(v6) => Ok(std::net::SocketAddr::V6(std::net::SocketAddrV6::new(
+                std::net::Ipv6Addr::from(v6.ip),
+                v6.port,
+                v6.flowinfo,
+                v6.scope_id,
+            ))),
+            SocketAddr::Unix(_) => Err(NotInetError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(addr: SocketAddr) {
+        let mut buf = [0u8; ADDR_BUF_SIZE];
+        let len = addr.write_raw(&mut buf);
+        assert_eq!(SocketAddr::from_raw(&buf, len), Some(addr));
+    }
+
+    #[test]
+    fn roundtrip_v4() {
+        roundtrip(SocketAddr::V4(SocketAddrV4 {
+            ip: [127, 0, 0, 1],
+            port: 8080,
+        }));
+    }
+
+    #[test]
+    fn roundtrip_v6() {
+        roundtrip(SocketAddr::V6(SocketAddrV6 {
+            ip: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+            port: 443,
+            flowinfo: 0,
+            scope_id: 0,
+        }));
+    }
+
+    #[test]
+    fn roundtrip_unix() {
+        roundtrip(SocketAddr::Unix(
+            SocketAddrUnix::new(b"/tmp/example.sock").unwrap(),
+        ));
+    }
+}